@@ -0,0 +1,135 @@
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+#[derive(Default, Debug, Clone, Deserialize, Serialize)]
+pub struct PrfOption {
+    /// for `remote` profile's http request
+    /// see issue #13
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_agent: Option<String>,
+
+    /// for `remote` profile
+    /// use system proxy
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub with_proxy: Option<bool>,
+
+    /// for `remote` profile
+    /// use self proxy
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub self_proxy: Option<bool>,
+
+    /// subscription update interval, in minutes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub update_interval: Option<u64>,
+
+    /// cron expression for wall-clock scheduling; takes precedence over
+    /// `update_interval` when set (e.g. `0 4 * * *` for every day at 04:00)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub update_cron: Option<String>,
+
+    /// base retry delay in seconds for failed updates (default 30)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_base_secs: Option<u64>,
+
+    /// maximum retry delay in seconds, capping the backoff (default 600)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_max_delay_secs: Option<u64>,
+
+    /// maximum number of attempts per scheduled run (default 5)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_max_attempts: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub danger_accept_invalid_certs: Option<bool>,
+}
+
+impl PrfOption {
+    pub fn merge(one: Option<Self>, other: Option<Self>) -> Option<Self> {
+        match (one, other) {
+            (Some(mut a), Some(b)) => {
+                a.user_agent = b.user_agent.or(a.user_agent);
+                a.with_proxy = b.with_proxy.or(a.with_proxy);
+                a.self_proxy = b.self_proxy.or(a.self_proxy);
+                a.update_interval = b.update_interval.or(a.update_interval);
+                a.update_cron = b.update_cron.or(a.update_cron);
+                a.retry_base_secs = b.retry_base_secs.or(a.retry_base_secs);
+                a.retry_max_delay_secs = b.retry_max_delay_secs.or(a.retry_max_delay_secs);
+                a.retry_max_attempts = b.retry_max_attempts.or(a.retry_max_attempts);
+                a.danger_accept_invalid_certs =
+                    b.danger_accept_invalid_certs.or(a.danger_accept_invalid_certs);
+                Some(a)
+            }
+            t => t.0.or(t.1),
+        }
+    }
+
+    /// Validate the option before it is persisted, surfacing any bad
+    /// `update_cron` to the caller instead of silently dropping the task
+    /// later when the scheduler fails to parse it.
+    pub fn validate(&self) -> Result<()> {
+        if let Some(cron) = self.update_cron.as_ref() {
+            let cron = cron.trim();
+            if !cron.is_empty() {
+                normalize_cron(cron)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Normalize a cron expression to the 6/7-field form the scheduler expects
+/// and validate it.
+///
+/// `delay_timer` and the `cron` crate both require a leading seconds field
+/// (and allow a trailing year), so the natural 5-field form such as
+/// `0 4 * * *` is expanded to `0 0 4 * * *`. Returns the normalized string
+/// or an error describing why the expression is invalid.
+///
+/// Note: only the seconds field is added — the remaining fields keep the
+/// `cron` crate's semantics, which are **not** POSIX for day-of-week:
+/// Sunday is `1` and Saturday is `7` (or three-letter names), whereas POSIX
+/// uses `0..6`. So weekdays are written `0 9 * * 2-6` (Mon–Fri), not the
+/// POSIX `0 9 * * 1-5`. This is pinned by the `dow_convention_is_cron_crate`
+/// test below.
+pub fn normalize_cron(expr: &str) -> Result<String> {
+    let expr = expr.trim();
+    let normalized = match expr.split_whitespace().count() {
+        5 => format!("0 {expr}"),
+        6 | 7 => expr.to_string(),
+        n => bail!("cron expression must have 5-7 fields, got {n}: `{expr}`"),
+    };
+
+    cron::Schedule::from_str(&normalized)
+        .with_context(|| format!("invalid cron expression: `{expr}`"))?;
+
+    Ok(normalized)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Datelike, TimeZone, Utc, Weekday};
+
+    #[test]
+    fn seconds_field_is_prepended() {
+        assert_eq!(normalize_cron("0 4 * * *").unwrap(), "0 0 4 * * *");
+        assert_eq!(normalize_cron("0 0 4 * * *").unwrap(), "0 0 4 * * *");
+    }
+
+    #[test]
+    fn dow_convention_is_cron_crate() {
+        // The `cron` crate numbers days Sun=1..Sat=7, so "weekdays" is
+        // `2-6` (Mon–Fri) — NOT the POSIX `1-5`. Pin that mapping here.
+        let expr = normalize_cron("0 9 * * 2-6").unwrap();
+        let schedule = cron::Schedule::from_str(&expr).unwrap();
+
+        let after = Utc.with_ymd_and_hms(2026, 7, 25, 0, 0, 0).unwrap(); // a Saturday
+        let days: Vec<Weekday> = schedule.after(&after).take(5).map(|dt| dt.weekday()).collect();
+
+        assert!(
+            days.iter().all(|d| !matches!(d, Weekday::Sat | Weekday::Sun)),
+            "cron `2-6` must map to Mon–Fri, got {days:?}"
+        );
+    }
+}
@@ -2,11 +2,17 @@ use crate::config::Config;
 use crate::feat;
 use crate::core::CoreManager;
 use anyhow::{Context, Result};
+use chrono::TimeZone;
 use delay_timer::prelude::{DelayTimer, DelayTimerBuilder, TaskBuilder};
 use once_cell::sync::OnceCell;
 use parking_lot::Mutex;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
 
 type TaskID = u64;
 
@@ -15,11 +21,14 @@ pub struct Timer {
     delay_timer: Arc<Mutex<DelayTimer>>,
 
     /// save the current state
-    timer_map: Arc<Mutex<HashMap<String, (TaskID, u64)>>>,
+    timer_map: Arc<Mutex<HashMap<String, (TaskID, ScheduleSpec)>>>,
 
     /// increment id
     timer_count: Arc<Mutex<TaskID>>,
 
+    /// per-task control channels, keyed by uid
+    controls: Arc<Mutex<HashMap<String, Arc<TaskControl>>>>,
+
     /// 标记定时器是否已经初始化
     initialized: Arc<Mutex<bool>>,
 }
@@ -32,6 +41,7 @@ impl Timer {
             delay_timer: Arc::new(Mutex::new(DelayTimerBuilder::default().build())),
             timer_map: Arc::new(Mutex::new(HashMap::new())),
             timer_count: Arc::new(Mutex::new(1)),
+            controls: Arc::new(Mutex::new(HashMap::new())),
             initialized: Arc::new(Mutex::new(false)),
         })
     }
@@ -45,6 +55,7 @@ impl Timer {
         }
 
         log::info!(target: "app", "Initializing timer...");
+        WorkerRegistry::global().hydrate();
         self.refresh()?;
 
         let cur_timestamp = chrono::Local::now().timestamp();
@@ -55,10 +66,31 @@ impl Timer {
             items
                 .iter()
                 .filter_map(|item| {
-                    let interval = ((item.option.as_ref()?.update_interval?) as i64) * 60;
-                    let updated = item.updated? as i64;
+                    let spec = ScheduleSpec::from_option(item.option.as_ref()?)?;
+                    let uid = item.uid.as_ref()?;
+                    let record = WorkerRegistry::global().record(uid);
+
+                    // don't hammer a repeatedly-failing feed on every launch
+                    if record
+                        .as_ref()
+                        .is_some_and(|r| r.consecutive_failures >= 3)
+                    {
+                        log::info!(
+                            target: "app",
+                            "Skipping startup catch-up for repeatedly-failing uid: {}", uid
+                        );
+                        return None;
+                    }
+
+                    // base the overdue decision on the last *successful*
+                    // update — a failed attempt must not suppress catch-up,
+                    // since the feed still hasn't been refreshed
+                    let updated = match record.as_ref().and_then(|r| r.last_success) {
+                        Some(ts) => ts,
+                        None => item.updated? as i64,
+                    };
 
-                    if interval > 0 && cur_timestamp - updated >= interval {
+                    if spec.is_overdue(updated, cur_timestamp) {
                         Some(item)
                     } else {
                         None
@@ -91,16 +123,19 @@ impl Timer {
             match diff {
                 DiffFlag::Del(tid) => {
                     let _ = timer_map.remove(&uid);
+                    let _ = self.controls.lock().remove(&uid);
                     crate::log_err!(delay_timer.remove_task(tid));
                 }
                 DiffFlag::Add(tid, val) => {
-                    let _ = timer_map.insert(uid.clone(), (tid, val));
-                    crate::log_err!(self.add_task(&mut delay_timer, uid, tid, val));
+                    let _ = timer_map.insert(uid.clone(), (tid, val.clone()));
+                    crate::log_err!(self.add_task(&mut delay_timer, uid.clone(), tid, val));
+                    self.register_control(uid);
                 }
                 DiffFlag::Mod(tid, val) => {
-                    let _ = timer_map.insert(uid.clone(), (tid, val));
+                    let _ = timer_map.insert(uid.clone(), (tid, val.clone()));
                     crate::log_err!(delay_timer.remove_task(tid));
-                    crate::log_err!(self.add_task(&mut delay_timer, uid, tid, val));
+                    crate::log_err!(self.add_task(&mut delay_timer, uid.clone(), tid, val));
+                    self.register_control(uid);
                 }
             }
         }
@@ -108,18 +143,15 @@ impl Timer {
         Ok(())
     }
 
-    /// generate a uid -> update_interval map
-    fn gen_map(&self) -> HashMap<String, u64> {
+    /// generate a uid -> schedule-spec map
+    fn gen_map(&self) -> HashMap<String, ScheduleSpec> {
         let mut new_map = HashMap::new();
 
         if let Some(items) = Config::profiles().latest().get_items() {
             for item in items.iter() {
-                if item.option.is_some() {
-                    let option = item.option.as_ref().unwrap();
-                    let interval = option.update_interval.unwrap_or(0);
-
-                    if interval > 0 {
-                        new_map.insert(item.uid.clone().unwrap(), interval);
+                if let Some(option) = item.option.as_ref() {
+                    if let Some(spec) = ScheduleSpec::from_option(option) {
+                        new_map.insert(item.uid.clone().unwrap(), spec);
                     }
                 }
             }
@@ -137,21 +169,23 @@ impl Timer {
         let new_map = self.gen_map();
         let cur_map = &timer_map;
 
-        cur_map.iter().for_each(|(uid, (tid, val))| {
-            let new_val = new_map.get(uid).unwrap_or(&0);
-
-            if *new_val == 0 {
-                diff_map.insert(uid.clone(), DiffFlag::Del(*tid));
-            } else if new_val != val {
-                diff_map.insert(uid.clone(), DiffFlag::Mod(*tid, *new_val));
+        cur_map.iter().for_each(|(uid, (tid, spec))| {
+            match new_map.get(uid) {
+                None => {
+                    diff_map.insert(uid.clone(), DiffFlag::Del(*tid));
+                }
+                Some(new_spec) if new_spec != spec => {
+                    diff_map.insert(uid.clone(), DiffFlag::Mod(*tid, new_spec.clone()));
+                }
+                Some(_) => {}
             }
         });
 
         let mut count = self.timer_count.lock();
 
-        new_map.iter().for_each(|(uid, val)| {
+        new_map.iter().for_each(|(uid, spec)| {
             if cur_map.get(uid).is_none() {
-                diff_map.insert(uid.clone(), DiffFlag::Add(*count, *val));
+                diff_map.insert(uid.clone(), DiffFlag::Add(*count, spec.clone()));
 
                 *count += 1;
             }
@@ -166,14 +200,24 @@ impl Timer {
         delay_timer: &mut DelayTimer,
         uid: String,
         tid: TaskID,
-        minutes: u64,
+        spec: ScheduleSpec,
     ) -> Result<()> {
-        log::info!(target: "app", "Adding new task: uid={}, interval={} minutes", uid, minutes);
+        log::info!(target: "app", "Adding new task: uid={}, schedule={:?}", uid, spec);
 
-        let task = TaskBuilder::default()
+        let mut builder = TaskBuilder::default();
+        builder
             .set_task_id(tid)
-            .set_maximum_parallel_runnable_num(1)
-            .set_frequency_repeated_by_minutes(minutes)
+            .set_maximum_parallel_runnable_num(1);
+        match &spec {
+            ScheduleSpec::Interval(minutes) => {
+                builder.set_frequency_repeated_by_minutes(*minutes);
+            }
+            ScheduleSpec::Cron(expr) => {
+                builder.set_frequency_repeated_by_cron_str(expr);
+            }
+        }
+
+        let task = builder
             .spawn_async_routine(move || {
                 let uid = uid.clone();
                 async move {
@@ -190,31 +234,595 @@ impl Timer {
         Ok(())
     }
 
-    /// the task runner
-    async fn async_task(uid: String) {
-        log::info!(target: "app", "Running timer task `{}`", uid);
+    /// register a control channel for a task and spawn its listener
+    fn register_control(&self, uid: String) {
+        let (ctrl_tx, mut ctrl_rx) = mpsc::unbounded_channel::<Ctrl>();
+        let paused = Arc::new(AtomicBool::new(false));
+        let in_flight = Arc::new(Mutex::new(None::<tokio::task::AbortHandle>));
 
-        match feat::update_profile(uid.clone(), None).await {
-            Ok(_) => {
-                match CoreManager::global().update_config().await {
-                    Ok(_) => {
-                        log::info!(target: "app", "Timer task completed successfully for uid: {}", uid);
+        let control = Arc::new(TaskControl {
+            paused: paused.clone(),
+            ctrl_tx,
+            in_flight: in_flight.clone(),
+        });
+        self.controls.lock().insert(uid.clone(), control);
+
+        tokio::spawn(async move {
+            while let Some(ctrl) = ctrl_rx.recv().await {
+                match ctrl {
+                    Ctrl::Pause => {
+                        log::info!(target: "app", "Pausing timer task `{}`", uid);
+                        paused.store(true, Ordering::SeqCst);
                     }
-                    Err(e) => {
-                        log::error!(target: "app", "Timer task refresh error for uid {}: {}", uid, e);
+                    Ctrl::Resume => {
+                        log::info!(target: "app", "Resuming timer task `{}`", uid);
+                        paused.store(false, Ordering::SeqCst);
+                    }
+                    Ctrl::RunNow => {
+                        // bypass the paused flag and the interval entirely
+                        log::info!(target: "app", "Triggering timer task `{}` now", uid);
+                        tokio::spawn(Timer::run_update(uid.clone(), true));
                     }
+                    Ctrl::CancelInFlight => {
+                        if let Some(handle) = in_flight.lock().take() {
+                            log::info!(target: "app", "Cancelling in-flight task `{}`", uid);
+                            handle.abort();
+                            // the aborted task can't run its own cleanup, so
+                            // settle the worker state here instead of leaving
+                            // it stuck in `Active`
+                            WorkerRegistry::global().mark_cancelled(&uid);
+                        }
+                    }
+                }
+            }
+        });
+    }
+
+    /// pause a task: it stays registered but skips its scheduled fire
+    pub fn pause_task(&self, uid: &str) -> Result<()> {
+        self.send_ctrl(uid, Ctrl::Pause)
+    }
+
+    /// resume a previously paused task
+    pub fn resume_task(&self, uid: &str) -> Result<()> {
+        self.send_ctrl(uid, Ctrl::Resume)
+    }
+
+    /// trigger an immediate update regardless of the interval
+    pub fn trigger_now(&self, uid: &str) -> Result<()> {
+        self.send_ctrl(uid, Ctrl::RunNow)
+    }
+
+    /// abort the currently running update for a task, if any
+    pub fn cancel_in_flight(&self, uid: &str) -> Result<()> {
+        self.send_ctrl(uid, Ctrl::CancelInFlight)
+    }
+
+    fn send_ctrl(&self, uid: &str, ctrl: Ctrl) -> Result<()> {
+        let control = self
+            .controls
+            .lock()
+            .get(uid)
+            .cloned()
+            .with_context(|| format!("no timer task registered for uid: {uid}"))?;
+        control
+            .ctrl_tx
+            .send(ctrl)
+            .map_err(|_| anyhow::anyhow!("control channel closed for uid: {uid}"))?;
+        Ok(())
+    }
+
+    /// the scheduled task runner — respects the task's paused flag
+    async fn async_task(uid: String) {
+        Self::run_update(uid, false).await;
+    }
+
+    /// run a single profile update for `uid`.
+    ///
+    /// When `force` is false (a scheduled fire) the task's paused flag is
+    /// honoured and the run is skipped while paused; a manual `RunNow`
+    /// passes `force = true` so it triggers immediately regardless of the
+    /// pause state or the interval. The update runs as an abortable task so
+    /// `CancelInFlight` can stop it mid-flight.
+    async fn run_update(uid: String, force: bool) {
+        let control = Self::global().controls.lock().get(&uid).cloned();
+
+        if !force {
+            if let Some(ctrl) = &control {
+                if ctrl.paused.load(Ordering::SeqCst) {
+                    log::info!(target: "app", "Timer task `{}` is paused, skipping", uid);
+                    return;
                 }
             }
-            Err(e) => {
-                log::error!(target: "app", "Timer task update error for uid {}: {}", uid, e);
+        }
+
+        let worker = ProfileUpdateWorker::new(uid.clone());
+        let policy = RetryPolicy::from_uid(&uid);
+        let join = tokio::spawn(async move {
+            crate::log_err!(WorkerRegistry::global().run_with_retry(&worker, &policy).await);
+        });
+
+        if let Some(ctrl) = &control {
+            *ctrl.in_flight.lock() = Some(join.abort_handle());
+        }
+        let _ = join.await;
+        if let Some(ctrl) = &control {
+            *ctrl.in_flight.lock() = None;
+        }
+    }
+}
+
+/// Exponential-backoff retry policy applied to a failed profile update.
+///
+/// Delays follow `base * 2^attempt`, capped at `max_delay`, with ±20%
+/// random jitter so subscriptions sharing a provider don't retry in
+/// lockstep. Values come from the profile `option`, falling back to
+/// sensible defaults (30s base, 10m cap, 5 attempts).
+pub struct RetryPolicy {
+    base: Duration,
+    max_delay: Duration,
+    max_attempts: u32,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            base: Duration::from_secs(30),
+            max_delay: Duration::from_secs(600),
+            max_attempts: 5,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// build a policy from the profile `option`, or defaults if absent
+    fn from_uid(uid: &str) -> Self {
+        let mut policy = RetryPolicy::default();
+
+        if let Some(items) = Config::profiles().latest().get_items() {
+            if let Some(option) = items
+                .iter()
+                .find(|item| item.uid.as_deref() == Some(uid))
+                .and_then(|item| item.option.as_ref())
+            {
+                if let Some(base) = option.retry_base_secs {
+                    policy.base = Duration::from_secs(base);
+                }
+                if let Some(max) = option.retry_max_delay_secs {
+                    policy.max_delay = Duration::from_secs(max);
+                }
+                if let Some(attempts) = option.retry_max_attempts {
+                    policy.max_attempts = attempts;
+                }
             }
         }
+
+        policy
+    }
+
+    /// compute the backoff delay after the given (0-based) attempt has failed
+    fn delay_for(&self, attempt: u32) -> Duration {
+        let base = self.base.as_secs_f64();
+        let raw = base * 2f64.powi(attempt as i32);
+        let capped = raw.min(self.max_delay.as_secs_f64());
+        let jitter = rand::thread_rng().gen_range(-0.2..=0.2);
+        Duration::from_secs_f64((capped * (1.0 + jitter)).max(0.0))
+    }
+}
+
+/// control messages understood by a task's control listener
+#[derive(Debug)]
+enum Ctrl {
+    Pause,
+    Resume,
+    CancelInFlight,
+    RunNow,
+}
+
+/// per-task control handle stored alongside each `timer_map` entry
+struct TaskControl {
+    /// whether scheduled fires should be skipped
+    paused: Arc<AtomicBool>,
+    /// sender feeding the task's control listener
+    ctrl_tx: mpsc::UnboundedSender<Ctrl>,
+    /// abort handle for the currently running update, if any
+    in_flight: Arc<Mutex<Option<tokio::task::AbortHandle>>>,
+}
+
+/// lifecycle state of a tracked [`BackgroundWorker`]
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "status", rename_all = "camelCase")]
+pub enum WorkerState {
+    /// registered but not currently running
+    Idle,
+    /// a run is in flight, started at the given unix timestamp
+    Active { started_at: i64 },
+    /// the last run failed with the given error at the given unix timestamp
+    Dead { error: String, at: i64 },
+}
+
+/// A recurring job tracked by the [`WorkerRegistry`].
+///
+/// Implementors describe *what* they do (`work`) and *who* they are
+/// (`name`); the registry owns the bookkeeping — state transitions, run
+/// counts and failure streaks — so individual jobs stay free of logging
+/// boilerplate.
+#[async_trait::async_trait]
+pub trait BackgroundWorker: Send + Sync {
+    /// stable key used to look up this worker's tracked handle
+    fn key(&self) -> String;
+
+    /// human-readable label shown in the worker-status view
+    fn name(&self) -> String;
+
+    /// perform one unit of work
+    async fn work(&self) -> Result<()>;
+}
+
+/// The recurring profile-update job driven by [`Timer::async_task`].
+struct ProfileUpdateWorker {
+    uid: String,
+}
+
+impl ProfileUpdateWorker {
+    fn new(uid: String) -> Self {
+        Self { uid }
+    }
+}
+
+#[async_trait::async_trait]
+impl BackgroundWorker for ProfileUpdateWorker {
+    fn key(&self) -> String {
+        self.uid.clone()
+    }
+
+    fn name(&self) -> String {
+        format!("profile-update:{}", self.uid)
+    }
+
+    async fn work(&self) -> Result<()> {
+        feat::update_profile(self.uid.clone(), None).await?;
+        CoreManager::global()
+            .update_config()
+            .await
+            .context("failed to refresh core config after profile update")?;
+        Ok(())
+    }
+}
+
+/// Per-worker bookkeeping kept by the [`WorkerRegistry`].
+struct WorkerHandle {
+    name: String,
+    state: Mutex<WorkerState>,
+    total_runs: AtomicU64,
+    consecutive_failures: AtomicU32,
+    last_attempt: Mutex<Option<i64>>,
+    last_success: Mutex<Option<i64>>,
+    last_error: Mutex<Option<String>>,
+}
+
+impl WorkerHandle {
+    fn new(name: String) -> Self {
+        Self {
+            name,
+            state: Mutex::new(WorkerState::Idle),
+            total_runs: AtomicU64::new(0),
+            consecutive_failures: AtomicU32::new(0),
+            last_attempt: Mutex::new(None),
+            last_success: Mutex::new(None),
+            last_error: Mutex::new(None),
+        }
+    }
+
+    /// snapshot this handle's counters as a persistable record
+    fn to_record(&self) -> RunRecord {
+        RunRecord {
+            last_attempt: *self.last_attempt.lock(),
+            last_success: *self.last_success.lock(),
+            last_error: self.last_error.lock().clone(),
+            run_count: self.total_runs.load(Ordering::SeqCst),
+            consecutive_failures: self.consecutive_failures.load(Ordering::SeqCst),
+        }
+    }
+}
+
+/// Durable last-run metadata for one uid, persisted across restarts.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RunRecord {
+    pub last_attempt: Option<i64>,
+    pub last_success: Option<i64>,
+    pub last_error: Option<String>,
+    pub run_count: u64,
+    pub consecutive_failures: u32,
+}
+
+/// Snapshot of a single worker, safe to hand to the Tauri command layer.
+#[derive(Debug, Clone, Serialize)]
+pub struct WorkerStatus {
+    pub key: String,
+    pub name: String,
+    pub state: WorkerState,
+    pub total_runs: u64,
+    pub consecutive_failures: u32,
+    pub last_attempt: Option<i64>,
+    pub last_success: Option<i64>,
+    pub last_error: Option<String>,
+}
+
+/// Global registry wrapping every recurring job so its state can be polled.
+pub struct WorkerRegistry {
+    workers: Mutex<HashMap<String, Arc<WorkerHandle>>>,
+    /// serializes the read-modify-write of the persisted state file
+    persist_lock: Mutex<()>,
+}
+
+impl WorkerRegistry {
+    pub fn global() -> &'static WorkerRegistry {
+        static REGISTRY: OnceCell<WorkerRegistry> = OnceCell::new();
+
+        REGISTRY.get_or_init(|| WorkerRegistry {
+            workers: Mutex::new(HashMap::new()),
+            persist_lock: Mutex::new(()),
+        })
+    }
+
+    fn handle(&self, key: &str, name: &str) -> Arc<WorkerHandle> {
+        let mut workers = self.workers.lock();
+        workers
+            .entry(key.to_string())
+            .or_insert_with(|| Arc::new(WorkerHandle::new(name.to_string())))
+            .clone()
+    }
+
+    /// Run a worker with retries, backing off exponentially between attempts.
+    ///
+    /// A single [`WorkerState::Active`] span covers the whole retry sequence;
+    /// it ends in [`WorkerState::Idle`] on the first success (resetting the
+    /// failure streak) or [`WorkerState::Dead`] once `max_attempts` is
+    /// exhausted, leaving the profile to be retried on its next scheduled run.
+    pub async fn run_with_retry(
+        &self,
+        worker: &dyn BackgroundWorker,
+        policy: &RetryPolicy,
+    ) -> Result<()> {
+        let handle = self.handle(&worker.key(), &worker.name());
+
+        let started_at = chrono::Local::now().timestamp();
+        *handle.state.lock() = WorkerState::Active { started_at };
+        *handle.last_attempt.lock() = Some(started_at);
+        // one "run" per scheduled invocation, not per retry attempt
+        handle.total_runs.fetch_add(1, Ordering::SeqCst);
+
+        let mut attempt: u32 = 0;
+        loop {
+            match worker.work().await {
+                Ok(_) => {
+                    handle.consecutive_failures.store(0, Ordering::SeqCst);
+                    *handle.last_success.lock() = Some(chrono::Local::now().timestamp());
+                    *handle.last_error.lock() = None;
+                    *handle.state.lock() = WorkerState::Idle;
+                    log::info!(target: "app", "Worker `{}` completed", worker.name());
+                    self.persist(&worker.key());
+                    return Ok(());
+                }
+                Err(e) => {
+                    *handle.last_error.lock() = Some(e.to_string());
+
+                    if attempt + 1 >= policy.max_attempts {
+                        // count one failure per scheduled run, once the
+                        // whole retry sequence is exhausted
+                        handle.consecutive_failures.fetch_add(1, Ordering::SeqCst);
+                        *handle.state.lock() = WorkerState::Dead {
+                            error: e.to_string(),
+                            at: chrono::Local::now().timestamp(),
+                        };
+                        log::error!(
+                            target: "app",
+                            "Worker `{}` gave up after {} attempts: {}",
+                            worker.name(), attempt + 1, e
+                        );
+                        self.persist(&worker.key());
+                        return Err(e);
+                    }
+
+                    let delay = policy.delay_for(attempt);
+                    log::warn!(
+                        target: "app",
+                        "Worker `{}` failed (attempt {}): {} — retrying in {:.1}s",
+                        worker.name(), attempt + 1, e, delay.as_secs_f64()
+                    );
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+            }
+        }
+    }
+
+    /// Settle a worker's state after its in-flight run was aborted.
+    ///
+    /// An aborted task is dropped mid-await and never reaches the
+    /// success/failure bookkeeping in [`run_with_retry`], so the caller
+    /// records the cancellation here to keep `list_workers` honest.
+    pub fn mark_cancelled(&self, key: &str) {
+        if let Some(handle) = self.workers.lock().get(key) {
+            let at = chrono::Local::now().timestamp();
+            *handle.last_error.lock() = Some("update cancelled".to_string());
+            *handle.state.lock() = WorkerState::Dead {
+                error: "update cancelled".to_string(),
+                at,
+            };
+        }
+        self.persist(key);
+    }
+
+    /// Snapshot every registered worker for polling by the frontend.
+    pub fn list_workers(&self) -> Vec<WorkerStatus> {
+        let workers = self.workers.lock();
+        workers
+            .iter()
+            .map(|(key, handle)| WorkerStatus {
+                key: key.clone(),
+                name: handle.name.clone(),
+                state: handle.state.lock().clone(),
+                total_runs: handle.total_runs.load(Ordering::SeqCst),
+                consecutive_failures: handle.consecutive_failures.load(Ordering::SeqCst),
+                last_attempt: *handle.last_attempt.lock(),
+                last_success: *handle.last_success.lock(),
+                last_error: handle.last_error.lock().clone(),
+            })
+            .collect()
+    }
+
+    /// path of the persisted timer-state file
+    fn state_path() -> Result<std::path::PathBuf> {
+        Ok(crate::utils::dirs::app_home_dir()?.join("timer_state.json"))
+    }
+
+    /// load every persisted [`RunRecord`], keyed by uid (empty if none yet)
+    fn load_records() -> HashMap<String, RunRecord> {
+        let path = match Self::state_path() {
+            Ok(path) if path.exists() => path,
+            _ => return HashMap::new(),
+        };
+        std::fs::read_to_string(&path)
+            .ok()
+            .and_then(|raw| serde_json::from_str(&raw).ok())
+            .unwrap_or_default()
+    }
+
+    /// write the record for a single uid back to disk, merging with the rest
+    ///
+    /// The whole load-modify-write is guarded by `persist_lock` so two
+    /// workers persisting different uids can't clobber each other, and the
+    /// file is written to a temp path then renamed so a crash mid-write
+    /// never leaves a truncated state file behind.
+    fn persist(&self, key: &str) {
+        let record = match self.workers.lock().get(key) {
+            Some(handle) => handle.to_record(),
+            None => return,
+        };
+
+        let _guard = self.persist_lock.lock();
+
+        let mut records = Self::load_records();
+        records.insert(key.to_string(), record);
+
+        crate::log_err!(Self::state_path().and_then(|path| {
+            let raw = serde_json::to_string_pretty(&records)?;
+            let tmp = path.with_extension("json.tmp");
+            std::fs::write(&tmp, raw)?;
+            std::fs::rename(&tmp, &path)?;
+            Ok(())
+        }));
+    }
+
+    /// Seed the registry from persisted records so `list_workers` and
+    /// catch-up decisions reflect history immediately after launch.
+    pub fn hydrate(&self) {
+        let records = Self::load_records();
+        let mut workers = self.workers.lock();
+
+        for (uid, record) in records {
+            let handle = WorkerHandle::new(format!("profile-update:{uid}"));
+            handle.total_runs.store(record.run_count, Ordering::SeqCst);
+            handle
+                .consecutive_failures
+                .store(record.consecutive_failures, Ordering::SeqCst);
+            *handle.last_attempt.lock() = record.last_attempt;
+            *handle.last_success.lock() = record.last_success;
+            *handle.last_error.lock() = record.last_error.clone();
+            if let (Some(error), Some(at)) = (record.last_error, record.last_attempt) {
+                if record.consecutive_failures > 0 {
+                    *handle.state.lock() = WorkerState::Dead { error, at };
+                }
+            }
+            workers.insert(uid, Arc::new(handle));
+        }
+    }
+
+    /// read the persisted/in-memory record for a uid, if any
+    pub fn record(&self, uid: &str) -> Option<RunRecord> {
+        self.workers.lock().get(uid).map(|handle| handle.to_record())
     }
 }
 
 #[derive(Debug)]
 enum DiffFlag {
     Del(TaskID),
-    Add(TaskID, u64),
-    Mod(TaskID, u64),
+    Add(TaskID, ScheduleSpec),
+    Mod(TaskID, ScheduleSpec),
+}
+
+/// How often a subscription's auto-update fires.
+///
+/// Either a fixed interval in minutes (`update_interval`) or a full cron
+/// expression (`update_cron`); a cron string takes precedence when both
+/// are set. Diffs are keyed on this value so changing the cron string —
+/// not just the interval — triggers a [`DiffFlag::Mod`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ScheduleSpec {
+    /// fixed interval, in minutes
+    Interval(u64),
+    /// cron expression understood by `delay_timer` / the `cron` crate
+    Cron(String),
+}
+
+impl ScheduleSpec {
+    /// derive the schedule from a profile `option`, if one is configured
+    fn from_option(option: &crate::config::PrfOption) -> Option<ScheduleSpec> {
+        if let Some(cron) = option.update_cron.as_ref() {
+            let cron = cron.trim();
+            if !cron.is_empty() {
+                match crate::config::normalize_cron(cron) {
+                    Ok(normalized) => return Some(ScheduleSpec::Cron(normalized)),
+                    Err(e) => log::error!(
+                        target: "app",
+                        "ignoring invalid cron `{}`, falling back to interval: {}", cron, e
+                    ),
+                }
+            }
+        }
+
+        match option.update_interval {
+            Some(interval) if interval > 0 => Some(ScheduleSpec::Interval(interval)),
+            _ => None,
+        }
+    }
+
+    /// whether a scheduled fire was missed between `updated` and `now`
+    ///
+    /// For intervals this is the simple `now - updated >= interval` check;
+    /// for cron schedules we ask the `cron` crate for the first fire time
+    /// strictly after `updated` and treat the task as overdue when that
+    /// time has already passed.
+    fn is_overdue(&self, updated: i64, now: i64) -> bool {
+        match self {
+            ScheduleSpec::Interval(minutes) => {
+                let interval = (*minutes as i64) * 60;
+                interval > 0 && now - updated >= interval
+            }
+            ScheduleSpec::Cron(expr) => {
+                use std::str::FromStr;
+
+                let schedule = match cron::Schedule::from_str(expr) {
+                    Ok(schedule) => schedule,
+                    Err(e) => {
+                        log::error!(target: "app", "invalid cron expression `{}`: {}", expr, e);
+                        return false;
+                    }
+                };
+
+                let updated_dt = match chrono::Local.timestamp_opt(updated, 0).single() {
+                    Some(dt) => dt,
+                    None => return false,
+                };
+
+                schedule
+                    .after(&updated_dt)
+                    .next()
+                    .map(|next| next.timestamp() <= now)
+                    .unwrap_or(false)
+            }
+        }
+    }
 }
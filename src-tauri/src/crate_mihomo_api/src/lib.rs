@@ -1,9 +1,11 @@
 use reqwest::header::HeaderMap;
 use serde_json::json;
 use std::{
+    collections::HashMap,
     sync::{Arc, Mutex},
     time::Duration,
 };
+use tokio::sync::Semaphore;
 pub mod model;
 pub use model::{MihomoData, MihomoManager};
 
@@ -139,4 +141,105 @@ impl MihomoManager {
         let response = self.send_request("GET", url, None).await?;
         return Ok(response);
     }
+
+    /// Test the delay of a whole group of proxies through a bounded worker pool.
+    ///
+    /// Up to `concurrency` per-proxy delay requests are in flight at once (via a
+    /// `Semaphore`); when `tranquility` is set, that much time is inserted
+    /// between launches so the local mihomo core and the upstream 204 endpoint
+    /// aren't overwhelmed. Returns a `name -> latency-or-error` map, keeping the
+    /// partial results of nodes that timed out rather than failing the batch.
+    pub async fn test_group_delay(
+        &self,
+        names: Vec<String>,
+        test_url: Option<String>,
+        timeout: i32,
+        concurrency: usize,
+        tranquility: Option<Duration>,
+    ) -> HashMap<String, Result<u32, String>> {
+        let test_url =
+            test_url.unwrap_or("http://cp.cloudflare.com/generate_204".to_string());
+
+        // one shared client for the whole batch, matching the `send_request`
+        // idiom (default headers + no_proxy) instead of rebuilding per node
+        let client = match reqwest::ClientBuilder::new()
+            .default_headers(self.headers.clone())
+            .no_proxy()
+            .timeout(Duration::from_millis(timeout as u64 + 1000))
+            .build()
+        {
+            Ok(client) => Arc::new(client),
+            Err(e) => {
+                return names
+                    .into_iter()
+                    .map(|name| (name, Err(e.to_string())))
+                    .collect();
+            }
+        };
+
+        let semaphore = Arc::new(Semaphore::new(concurrency.max(1)));
+        let mut handles = Vec::with_capacity(names.len());
+
+        for name in names {
+            // rate limit: leave a small gap between launches
+            if let Some(delay) = tranquility {
+                tokio::time::sleep(delay).await;
+            }
+
+            let permit = semaphore
+                .clone()
+                .acquire_owned()
+                .await
+                .expect("delay-test semaphore closed");
+            let client = client.clone();
+            let server = self.mihomo_server.clone();
+            let test_url = test_url.clone();
+
+            handles.push(tokio::spawn(async move {
+                let _permit = permit;
+                let result =
+                    Self::request_proxy_delay(&client, &server, &name, &test_url, timeout).await;
+                (name, result)
+            }));
+        }
+
+        let mut results = HashMap::with_capacity(handles.len());
+        for handle in handles {
+            if let Ok((name, result)) = handle.await {
+                results.insert(name, result);
+            }
+        }
+        results
+    }
+
+    /// Issue a single `/proxies/{name}/delay` request and parse the latency.
+    async fn request_proxy_delay(
+        client: &reqwest::Client,
+        mihomo_server: &str,
+        name: &str,
+        test_url: &str,
+        timeout: i32,
+    ) -> Result<u32, String> {
+        let url = format!(
+            "{}/proxies/{}/delay?url={}&timeout={}",
+            mihomo_server, name, test_url, timeout
+        );
+        let response = client
+            .get(&url)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?
+            .json::<serde_json::Value>()
+            .await
+            .map_err(|e| e.to_string())?;
+
+        match response.get("delay").and_then(|d| d.as_u64()) {
+            Some(delay) => Ok(delay as u32),
+            None => Err(response
+                .get("message")
+                .and_then(|m| m.as_str())
+                .unwrap_or("unknown error")
+                .to_string()),
+        }
+    }
 }